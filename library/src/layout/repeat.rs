@@ -6,6 +6,12 @@ use super::AlignElem;
 ///
 /// This can be useful when implementing a custom index, reference, or outline.
 ///
+/// The content is repeated horizontally by default, but it can also be stacked
+/// vertically with the `axis` parameter. Along either axis, the repetition only
+/// takes up the full space if the region expands in that direction, for
+/// instance in a box with a fixed size. Otherwise, as many instances as fit are
+/// packed together and the repetition is only as large as those.
+///
 /// Space may be inserted between the instances of the body parameter, so be
 /// sure to include negative space if you need the instances to overlap.
 ///
@@ -31,8 +37,76 @@ pub struct RepeatElem {
     /// The content to repeat.
     #[required]
     pub body: Content,
+
+    /// The gap between each instance of the body.
+    ///
+    /// ```example
+    /// Chapter 1 #box(width: 1fr, repeat(gap: 3pt)[.]) 3
+    /// ```
+    #[resolve]
+    #[default]
+    pub gap: Length,
+
+    /// Whether to increase the gap between instances to completely fill the
+    /// available space.
+    ///
+    /// This only has an effect if the repetition is meant to take up the full
+    /// space, for instance in a box with a fractional width. Within an
+    /// automatically sized box, the instances are always packed.
+    ///
+    /// If this is `{false}`, the instances are packed together and positioned
+    /// according to the alignment along the repetition axis. By default, this
+    /// leaves the remaining space at the end.
+    ///
+    /// ```example
+    /// Chapter 1 #box(width: 1fr, repeat(justify: false)[.]) 3
+    /// ```
+    #[default(true)]
+    pub justify: bool,
+
+    /// The axis along which the body is repeated.
+    ///
+    /// By default, the body is repeated horizontally to fill the available
+    /// width. When repeating vertically, it fills the available height
+    /// instead, with the same gap and justification behaviour. It only
+    /// stretches to the full height if the region expands vertically, as in
+    /// a box with a fixed height.
+    ///
+    /// ```example
+    /// #box(height: 4em, repeat(axis: "vertical")[.])
+    /// ```
+    #[default(Axis::X)]
+    pub axis: Axis,
+
+    /// The maximum number of instances to place.
+    ///
+    /// When the limit is reached, the leftover space is handled as usual,
+    /// that is, it is distributed between the instances if `justify` is
+    /// enabled.
+    ///
+    /// The limit takes precedence over `at-least`, so no more instances than
+    /// the limit are placed even if the minimum is larger. Regardless of this
+    /// setting, at most 1000 instances are placed.
+    ///
+    /// ```example
+    /// Chapter 1 #box(width: 1fr, repeat(limit: 5)[.]) 3
+    /// ```
+    pub limit: Option<usize>,
+
+    /// The minimum number of instances to place.
+    ///
+    /// These instances are placed even if they don't fit into the available
+    /// space. In that case, the repetition reports its full size so that it
+    /// can be clipped by its container. If a `limit` is set and smaller than
+    /// the minimum, the limit wins. Like the limit, the minimum is capped at
+    /// 1000 instances.
+    #[default(1)]
+    pub at_least: usize,
 }
 
+/// The maximum number of instances a repetition places.
+const MAX_INSTANCES: usize = 1000;
+
 impl Layout for RepeatElem {
     #[tracing::instrument(name = "RepeatElem::layout", skip_all)]
     fn layout(
@@ -41,36 +115,85 @@ impl Layout for RepeatElem {
         styles: StyleChain,
         regions: Regions,
     ) -> SourceResult<Fragment> {
+        // Measure the body in the first region only and without expansion, so
+        // that it keeps its natural size instead of stretching to fill the
+        // region. Repetitions are never broken across regions. The pod keeps
+        // the region's size rather than an unbounded width because the base
+        // is derived from it: relatively sized bodies like `block(width: 50%)`
+        // must resolve against the available space.
         let pod = Regions::one(regions.size, Axes::new(false, false));
         let piece = self.body().layout(vt, styles, pod)?.into_frame();
-        let align = AlignElem::alignment_in(styles).x.resolve(styles);
+        let axis = self.axis(styles);
+        let align = AlignElem::alignment_in(styles).resolve(styles).get(axis);
 
-        let fill = regions.size.x;
-        let width = piece.width();
-        let count = (fill / width).floor();
-        let remaining = fill % width;
-        let apart = remaining / (count - 1.0);
+        let fill = regions.size.get(axis);
+        let extent = piece.size().get(axis);
+        let gap = self.gap(styles);
 
-        let size = Size::new(regions.size.x, piece.height());
+        let mut size = piece.size();
+        size.set(axis, fill);
 
         if !size.is_finite() {
             bail!(self.span(), "repeat with no size restrictions");
         }
 
-        let mut frame = Frame::new(size);
-        if piece.has_baseline() {
-            frame.set_baseline(piece.baseline());
+        // Determine how many instances fit. The gap is only inserted between
+        // instances, so `count` instances take up
+        // `count * extent + (count - 1) * gap`.
+        let mut count = 0.0;
+        if extent + gap > Abs::zero() {
+            count = ((fill + gap) / (extent + gap)).floor();
+        }
+
+        // Place the minimum number of instances even if they don't fit, but
+        // never more than the limit.
+        count = count.max(self.at_least(styles) as f64);
+        if let Some(limit) = self.limit(styles) {
+            count = count.min(limit as f64);
         }
 
-        let mut offset = Abs::zero();
-        if count == 1.0 {
-            offset += align.position(remaining);
+        // Bound the number of instances so that a huge minimum or a tiny body
+        // can't produce an unreasonably large frame.
+        count = count.min(MAX_INSTANCES as f64);
+
+        let mut used = Abs::zero();
+        if count > 0.0 {
+            used = extent * count + gap * (count - 1.0);
+        }
+
+        let remaining = (fill - used).max(Abs::zero());
+
+        // When justifying, the remaining space is distributed between the
+        // instances, so they span the full space. This only happens if the
+        // region asks us to expand, otherwise the instances stay packed.
+        let justify = self.justify(styles) && regions.expand.get(axis) && count > 1.0;
+        let mut apart = gap;
+        let mut span = used;
+        if justify {
+            apart += remaining / (count - 1.0);
+            span += remaining;
+        }
+
+        // Only take up the full space if the region asks us to expand, but
+        // always report the true size if the instances overflow.
+        if regions.expand.get(axis) {
+            size.set(axis, fill.max(span));
+        } else {
+            size.set(axis, span);
+        }
+
+        let mut frame = Frame::new(size);
+        if axis == Axis::X && piece.has_baseline() {
+            frame.set_baseline(piece.baseline());
         }
 
-        if width > Abs::zero() {
-            for _ in 0..(count as usize).min(1000) {
-                frame.push_frame(Point::with_x(offset), piece.clone());
-                offset += piece.width() + apart;
+        let mut offset = align.position(size.get(axis) - span);
+        if extent > Abs::zero() {
+            for _ in 0..count as usize {
+                let mut pos = Point::zero();
+                pos.set(axis, offset);
+                frame.push_frame(pos, piece.clone());
+                offset += extent + apart;
             }
         }
 
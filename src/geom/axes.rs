@@ -2,6 +2,7 @@ use std::any::Any;
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
 
 use super::*;
+use crate::eval::Cast;
 
 /// A container with a horizontal and vertical component.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
@@ -152,11 +153,13 @@ where
 }
 
 /// The two layouting axes.
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum Axis {
     /// The horizontal axis.
+    #[string("horizontal")]
     X,
     /// The vertical  axis.
+    #[string("vertical")]
     Y,
 }
 
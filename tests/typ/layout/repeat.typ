@@ -42,3 +42,132 @@ A#box(width: 1fr, repeat(rect(width: 6em, height: 0.7em)))B
 // Error: 2:2-2:13 repeat with no size restrictions
 #set page(width: auto)
 #repeat(".")
+
+---
+// Ref: false
+// Test regions fitting no, one and many instances.
+#set page(width: 100pt, margin: 0pt)
+#let at(x) = locate(loc => test(loc.position().x, x))
+
+#box(width: 20pt)[#box(repeat(box(width: 30pt)))#at(30pt)]
+
+#box(width: 50pt)[#box(repeat(box(width: 30pt)))#at(30pt)]
+
+#box(repeat(box(width: 30pt)))#at(90pt)
+
+#box(repeat[])#at(0pt)
+
+---
+// Ref: false
+// Test gap between instances.
+#set page(width: 100pt, margin: 0pt)
+#let at(x) = locate(loc => test(loc.position().x, x))
+
+#box(repeat(box(width: 10pt)))#at(100pt)
+
+#box(repeat(gap: 15pt, box(width: 10pt)))#at(85pt)
+
+#box(repeat(gap: 90pt, box(width: 10pt)))#at(10pt)
+
+---
+// Ref: false
+// Test packed instances in both directions.
+#set page(width: 100pt, margin: 0pt)
+#let at(x) = locate(loc => test(loc.position().x, x))
+#let piece(x) = box(width: 30pt, height: 5pt, place(left, at(x)))
+
+#box(width: 1fr, repeat(piece(0pt)))#at(100pt)
+
+#box(width: 1fr, repeat(justify: false, piece(0pt)))#at(100pt)
+
+#box(repeat(justify: false, piece(0pt)))#at(90pt)
+
+#set align(center)
+#box(width: 1fr, repeat(justify: false, piece(5pt)))
+
+#set align(start)
+#set text(dir: rtl)
+#box(width: 1fr, repeat(piece(0pt)))
+
+#box(width: 1fr, repeat(justify: false, piece(10pt)))
+
+---
+// Ref: false
+// Test vertical repeat in a fixed-height block.
+#set page(width: 100pt, margin: 0pt)
+#set block(spacing: 0pt)
+#let at(y) = locate(loc => test(loc.position().y, y))
+#let piece(y) = box(width: 5pt, height: 20pt, place(left, at(y)))
+
+#block(height: 100pt)[
+  #set align(bottom)
+  #repeat(axis: "vertical", justify: false, piece(0pt))
+]
+
+#block(height: 100pt)[
+  #set align(bottom)
+  #repeat(axis: "vertical", justify: false, gap: 5pt, piece(105pt))
+]
+
+#block(height: 100pt)[
+  #set align(bottom)
+  #repeat(axis: "vertical", justify: false, limit: 2, piece(260pt))
+]
+
+#block(height: 3cm, repeat(axis: "vertical")[.])
+
+#box(height: 30pt, repeat(axis: "vertical")[.])
+
+---
+// Error: 2:2-2:31 repeat with no size restrictions
+#set page(height: auto)
+#repeat(axis: "vertical", ".")
+
+---
+// Ref: false
+// Test limiting and forcing the number of instances.
+#set page(width: 100pt, margin: 0pt)
+#let at(x) = locate(loc => test(loc.position().x, x))
+
+#box(repeat(limit: 3, box(width: 10pt)))#at(30pt)
+
+#box(repeat(limit: 30, box(width: 10pt)))#at(100pt)
+
+#box(width: 20pt, clip: true)[#box(repeat(at-least: 4, box(width: 10pt)))#at(40pt)]
+
+#box(repeat(at-least: 5000, box(width: 1pt)))#at(1000pt)
+
+#box(repeat(limit: 2, at-least: 4, box(width: 10pt)))#at(20pt)
+
+---
+// Ref: false
+// Test that repeat only fills the space if the region expands.
+#set page(width: 100pt, margin: 0pt)
+#let at(x) = locate(loc => test(loc.position().x, x))
+#let piece(x) = box(width: 30pt, height: 5pt, place(left, at(x)))
+
+#box(repeat(piece(0pt)))#at(90pt)
+
+#box(repeat(justify: false, piece(0pt)))#at(90pt)
+
+#box(width: 1fr, repeat(piece(0pt)))#at(100pt)
+
+#box(repeat[.])#locate(loc => assert(loc.position().x < 100pt))
+
+#box(repeat(block(height: 5pt)))#at(0pt)
+
+#box(repeat(block(width: 50%, height: 5pt)))#at(100pt)
+
+#box(width: 1fr, repeat(block(width: 40%, height: 5pt)))#at(100pt)
+
+---
+// Ref: false
+// Test that dot leaders keep the trailing content flush with the line end.
+#set page(width: 100pt, margin: 0pt)
+#let at(x) = locate(loc => test(loc.position().x, x))
+#let number(x) = box(width: 20pt)[#place(top + left, at(x))42]
+
+Title #box(width: 1fr, repeat[.])#number(80pt)
+
+#set text(lang: "ar")
+مقدمة #box(width: 1fr, repeat[.])#number(0pt)